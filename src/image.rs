@@ -1,21 +1,141 @@
 use crate::FromSvgOptions;
 use crate::bindings::{
-    VipsImage as CVipsImage, g_object_unref, vips_image_get_blob, vips_image_get_height,
-    vips_image_get_n_pages, vips_image_get_typeof, vips_image_get_width, vips_image_hasalpha,
-    vips_image_new_from_file, vips_image_new_from_image, vips_image_set_kill, vips_svgload,
+    VipsImage as CVipsImage, g_free, g_object_unref, vips_autorot, vips_gifload,
+    vips_image_get_blob, vips_image_get_double, vips_image_get_height, vips_image_get_int,
+    vips_image_get_n_pages, vips_image_get_string, vips_image_get_typeof, vips_image_get_width,
+    vips_image_hasalpha, vips_image_new_from_buffer, vips_image_new_from_file,
+    vips_image_new_from_image, vips_image_remove, vips_image_set_kill,
+    vips_image_write_to_buffer, vips_image_write_to_file, vips_magickload, vips_pdfload,
+    vips_svgload, vips_thumbnail, vips_thumbnail_buffer, vips_tiffload, vips_webpload,
 };
+#[cfg(feature = "heif")]
+use crate::bindings::vips_heifload;
 use crate::options::FromFileOptions;
 use crate::result::{Error, Result};
 use crate::utils::c_string;
 use crate::vips::Vips;
+use std::ffi::CStr;
 use std::os::raw::{c_int, c_void};
 use std::ptr::null_mut;
 
+/// Wraps a `*mut CVipsImage` together with two keepalive slots: images this
+/// one was derived from (`.1`), and an owned buffer backing an in-memory
+/// load (`.2`). libvips never copies buffer-backed pixel data, so the
+/// `Vec<u8>` must outlive the image.
 #[derive(Debug, Clone)]
-pub struct VipsImage(pub *mut CVipsImage, pub(crate) Option<Vec<VipsImage>>);
+pub struct VipsImage(
+    pub *mut CVipsImage,
+    pub(crate) Option<Vec<VipsImage>>,
+    pub(crate) Option<Vec<u8>>,
+);
 
 pub const NULL: *const std::os::raw::c_char = std::ptr::null();
 
+/// Options for [`VipsImage::new_from_buffer`]. Mirrors [`FromFileOptions`]
+/// since libvips exposes the same `access`/`memory` knobs for both loaders.
+#[derive(Debug, Clone, Copy)]
+pub struct FromBufferOptions {
+    pub access: c_int,
+    pub memory: bool,
+}
+
+/// Options for [`VipsImage::new_from_document`]. `page` is the first page
+/// to load (0-indexed), `n` is how many pages to load (`-1` loads every
+/// remaining page), and `dpi`/`scale` control the rasterization resolution.
+#[derive(Debug, Clone, Copy)]
+pub struct DocumentOptions {
+    pub page: c_int,
+    pub n: c_int,
+    pub dpi: f64,
+    pub scale: f64,
+}
+
+impl Default for DocumentOptions {
+    fn default() -> Self {
+        DocumentOptions {
+            page: 0,
+            n: 1,
+            dpi: 72.0,
+            scale: 1.0,
+        }
+    }
+}
+
+/// Mirrors libvips' `VipsSize` enum: how the target `width`/`height` relate
+/// to an upscale or downscale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VipsSize {
+    Both = 0,
+    Up = 1,
+    Down = 2,
+    Force = 3,
+}
+
+/// Mirrors libvips' `VipsInteresting` enum: which region of the image
+/// `crop` should keep when the aspect ratio does not match the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VipsInteresting {
+    None = 0,
+    Centre = 1,
+    Entropy = 2,
+    Attention = 3,
+}
+
+/// Options for [`VipsImage::thumbnail`] and [`VipsImage::thumbnail_buffer`].
+#[derive(Debug, Clone, Copy)]
+pub struct ThumbnailOptions {
+    pub height: c_int,
+    pub size: VipsSize,
+    pub crop: VipsInteresting,
+    pub no_rotate: bool,
+}
+
+impl Default for ThumbnailOptions {
+    fn default() -> Self {
+        ThumbnailOptions {
+            height: 0,
+            size: VipsSize::Both,
+            crop: VipsInteresting::None,
+            no_rotate: false,
+        }
+    }
+}
+
+/// Options for [`VipsImage::new_from_heif`]. `page` selects a frame in a
+/// multi-image HEIF container (e.g. a burst shot); `thumbnail` pulls the
+/// small embedded preview instead of decoding the full-resolution image.
+#[cfg(feature = "heif")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeifOptions {
+    pub page: c_int,
+    pub thumbnail: bool,
+}
+
+/// Per-format encode options for [`VipsImage::write_to_file`] and
+/// [`VipsImage::write_to_buffer`], mirroring the vararg options each
+/// libvips save operation (`vips_jpegsave`, `vips_pngsave`, ...) accepts.
+#[derive(Debug, Clone, Copy)]
+pub enum SaveOptions {
+    Jpeg {
+        quality: c_int,
+        progressive: bool,
+        chroma_subsampling: bool,
+    },
+    Png {
+        compression: c_int,
+        palette: bool,
+    },
+    Webp {
+        quality: c_int,
+        lossless: bool,
+        effort: c_int,
+    },
+    Avif {
+        quality: c_int,
+        effort: c_int,
+    },
+}
+
 impl VipsImage {
     pub fn new_from_file(filename: &str, options: Option<FromFileOptions>) -> Result<Self> {
         let filename = match c_string(filename) {
@@ -41,7 +161,238 @@ impl VipsImage {
             return Err(Error::ImageLoadError(Vips::get_error()));
         }
 
-        Ok(VipsImage(image, None))
+        Ok(VipsImage(image, None, None))
+    }
+
+    pub fn new_from_buffer(bytes: &[u8], options: Option<FromBufferOptions>) -> Result<Self> {
+        let bytes = bytes.to_vec();
+
+        let image = match options {
+            Some(options) => unsafe {
+                vips_image_new_from_buffer(
+                    bytes.as_ptr() as *const c_void,
+                    bytes.len(),
+                    c_string("")?.as_ptr(),
+                    c_string("memory")?.as_ptr(),
+                    options.memory as c_int,
+                    c_string("access")?.as_ptr(),
+                    options.access,
+                    NULL,
+                )
+            },
+            None => unsafe {
+                vips_image_new_from_buffer(
+                    bytes.as_ptr() as *const c_void,
+                    bytes.len(),
+                    c_string("")?.as_ptr(),
+                    NULL,
+                )
+            },
+        };
+
+        if image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        let mut image = VipsImage(image, None, None);
+        image.2 = Some(bytes);
+
+        Ok(image)
+    }
+
+    pub fn thumbnail(filename: &str, width: i32, options: Option<ThumbnailOptions>) -> Result<Self> {
+        let filename = match c_string(filename) {
+            Ok(filename) => filename,
+            Err(e) => return Err(e),
+        };
+
+        let options = options.unwrap_or_default();
+        let mut output_image: *mut crate::bindings::VipsImage = null_mut();
+        // A height of 0 sizes by width alone (libvips' VIPS_MAX_COORD as the bound).
+        let height = if options.height > 0 { options.height } else { 10_000_000 };
+
+        let result = unsafe {
+            vips_thumbnail(
+                filename.as_ptr(),
+                &mut output_image,
+                width as c_int,
+                c_string("height")?.as_ptr(),
+                height,
+                c_string("size")?.as_ptr(),
+                options.size as c_int,
+                c_string("crop")?.as_ptr(),
+                options.crop as c_int,
+                c_string("no_rotate")?.as_ptr(),
+                options.no_rotate as c_int,
+                NULL,
+            )
+        };
+
+        if result != 0 || output_image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        Ok(VipsImage(output_image, None, None))
+    }
+
+    pub fn thumbnail_buffer(
+        bytes: &[u8],
+        width: i32,
+        options: Option<ThumbnailOptions>,
+    ) -> Result<Self> {
+        let bytes = bytes.to_vec();
+        let options = options.unwrap_or_default();
+        let mut output_image: *mut crate::bindings::VipsImage = null_mut();
+        // A height of 0 sizes by width alone (libvips' VIPS_MAX_COORD as the bound).
+        let height = if options.height > 0 { options.height } else { 10_000_000 };
+
+        let result = unsafe {
+            vips_thumbnail_buffer(
+                bytes.as_ptr() as *const c_void,
+                bytes.len(),
+                &mut output_image,
+                width as c_int,
+                c_string("height")?.as_ptr(),
+                height,
+                c_string("size")?.as_ptr(),
+                options.size as c_int,
+                c_string("crop")?.as_ptr(),
+                options.crop as c_int,
+                c_string("no_rotate")?.as_ptr(),
+                options.no_rotate as c_int,
+                NULL,
+            )
+        };
+
+        if result != 0 || output_image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        let mut image = VipsImage(output_image, None, None);
+        image.2 = Some(bytes);
+
+        Ok(image)
+    }
+
+    #[cfg(feature = "heif")]
+    pub fn new_from_heif(filename: &str, options: Option<HeifOptions>) -> Result<Self> {
+        let filename = match c_string(filename) {
+            Ok(filename) => filename,
+            Err(e) => return Err(e),
+        };
+
+        let options = options.unwrap_or_default();
+        let mut output_image: *mut crate::bindings::VipsImage = null_mut();
+
+        let result = unsafe {
+            vips_heifload(
+                filename.as_ptr(),
+                &mut output_image,
+                c_string("page")?.as_ptr(),
+                options.page,
+                c_string("thumbnail")?.as_ptr(),
+                options.thumbnail as c_int,
+                NULL,
+            )
+        };
+
+        if result != 0 || output_image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        Ok(VipsImage(output_image, None, None))
+    }
+
+    pub fn new_from_raw(filename: &str) -> Result<Self> {
+        let filename = match c_string(filename) {
+            Ok(filename) => filename,
+            Err(e) => return Err(e),
+        };
+
+        let mut output_image: *mut crate::bindings::VipsImage = null_mut();
+        let result = unsafe { vips_magickload(filename.as_ptr(), &mut output_image, NULL) };
+
+        if result != 0 || output_image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        Ok(VipsImage(output_image, None, None))
+    }
+
+    pub fn new_from_document(filename: &str, options: Option<DocumentOptions>) -> Result<Self> {
+        let filename_cstr = match c_string(filename) {
+            Ok(filename) => filename,
+            Err(e) => return Err(e),
+        };
+
+        let options = options.unwrap_or_default();
+        let mut output_image: *mut crate::bindings::VipsImage = null_mut();
+
+        let loader_result = unsafe {
+            match filename.rsplit('.').next().unwrap_or("").to_lowercase().as_str() {
+                "pdf" => vips_pdfload(
+                    filename_cstr.as_ptr(),
+                    &mut output_image,
+                    c_string("page")?.as_ptr(),
+                    options.page,
+                    c_string("n")?.as_ptr(),
+                    options.n,
+                    c_string("dpi")?.as_ptr(),
+                    options.dpi,
+                    c_string("scale")?.as_ptr(),
+                    options.scale,
+                    NULL,
+                ),
+                "tif" | "tiff" => vips_tiffload(
+                    filename_cstr.as_ptr(),
+                    &mut output_image,
+                    c_string("page")?.as_ptr(),
+                    options.page,
+                    c_string("n")?.as_ptr(),
+                    options.n,
+                    NULL,
+                ),
+                "gif" => vips_gifload(
+                    filename_cstr.as_ptr(),
+                    &mut output_image,
+                    c_string("page")?.as_ptr(),
+                    options.page,
+                    c_string("n")?.as_ptr(),
+                    options.n,
+                    NULL,
+                ),
+                "webp" => vips_webpload(
+                    filename_cstr.as_ptr(),
+                    &mut output_image,
+                    c_string("page")?.as_ptr(),
+                    options.page,
+                    c_string("n")?.as_ptr(),
+                    options.n,
+                    c_string("scale")?.as_ptr(),
+                    options.scale,
+                    NULL,
+                ),
+                _ => vips_pdfload(
+                    filename_cstr.as_ptr(),
+                    &mut output_image,
+                    c_string("page")?.as_ptr(),
+                    options.page,
+                    c_string("n")?.as_ptr(),
+                    options.n,
+                    c_string("dpi")?.as_ptr(),
+                    options.dpi,
+                    c_string("scale")?.as_ptr(),
+                    options.scale,
+                    NULL,
+                ),
+            }
+        };
+
+        if loader_result != 0 || output_image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        Ok(VipsImage(output_image, None, None))
     }
 
     pub fn new_from_svg(filename: &str, options: Option<FromSvgOptions>) -> Result<Self> {
@@ -83,7 +434,7 @@ impl VipsImage {
             return Err(Error::ImageLoadError(Vips::get_error()));
         }
 
-        Ok(VipsImage(output_image, None))
+        Ok(VipsImage(output_image, None, None))
     }
 
     pub fn new_from_image(image: &VipsImage, bands: &[f64]) -> Result<Self> {
@@ -94,7 +445,7 @@ impl VipsImage {
             return Err(Error::ImageLoadError(Vips::get_error()));
         }
 
-        Ok(VipsImage(image, None))
+        Ok(VipsImage(image, None, None))
     }
 
     pub fn new_from_self(&self, bands: &[f64]) -> Result<Self> {
@@ -146,6 +497,236 @@ impl VipsImage {
         Ok(blob_data)
     }
 
+    pub fn write_to_file(&self, filename: &str, options: Option<SaveOptions>) -> Result<()> {
+        let filename = match c_string(filename) {
+            Ok(filename) => filename,
+            Err(e) => return Err(e),
+        };
+
+        let result = unsafe {
+            match options {
+                Some(SaveOptions::Jpeg {
+                    quality,
+                    progressive,
+                    chroma_subsampling,
+                }) => vips_image_write_to_file(
+                    self.0,
+                    filename.as_ptr(),
+                    c_string("Q")?.as_ptr(),
+                    quality,
+                    c_string("interlace")?.as_ptr(),
+                    progressive as c_int,
+                    c_string("subsample_mode")?.as_ptr(),
+                    if chroma_subsampling { 1 } else { 2 } as c_int,
+                    NULL,
+                ),
+                Some(SaveOptions::Png {
+                    compression,
+                    palette,
+                }) => vips_image_write_to_file(
+                    self.0,
+                    filename.as_ptr(),
+                    c_string("compression")?.as_ptr(),
+                    compression,
+                    c_string("palette")?.as_ptr(),
+                    palette as c_int,
+                    NULL,
+                ),
+                Some(SaveOptions::Webp {
+                    quality,
+                    lossless,
+                    effort,
+                }) => vips_image_write_to_file(
+                    self.0,
+                    filename.as_ptr(),
+                    c_string("Q")?.as_ptr(),
+                    quality,
+                    c_string("lossless")?.as_ptr(),
+                    lossless as c_int,
+                    c_string("effort")?.as_ptr(),
+                    effort,
+                    NULL,
+                ),
+                Some(SaveOptions::Avif { quality, effort }) => vips_image_write_to_file(
+                    self.0,
+                    filename.as_ptr(),
+                    c_string("Q")?.as_ptr(),
+                    quality,
+                    c_string("effort")?.as_ptr(),
+                    effort,
+                    NULL,
+                ),
+                None => vips_image_write_to_file(self.0, filename.as_ptr(), NULL),
+            }
+        };
+
+        if result != 0 {
+            return Err(Error::ImageSaveError(Vips::get_error()));
+        }
+
+        Ok(())
+    }
+
+    pub fn write_to_buffer(&self, suffix: &str, options: Option<SaveOptions>) -> Result<Vec<u8>> {
+        let suffix = match c_string(suffix) {
+            Ok(suffix) => suffix,
+            Err(e) => return Err(e),
+        };
+
+        let mut output: *mut c_void = null_mut();
+        let mut length: usize = 0;
+
+        let result = unsafe {
+            match options {
+                Some(SaveOptions::Jpeg {
+                    quality,
+                    progressive,
+                    chroma_subsampling,
+                }) => vips_image_write_to_buffer(
+                    self.0,
+                    suffix.as_ptr(),
+                    &mut output,
+                    &mut length,
+                    c_string("Q")?.as_ptr(),
+                    quality,
+                    c_string("interlace")?.as_ptr(),
+                    progressive as c_int,
+                    c_string("subsample_mode")?.as_ptr(),
+                    if chroma_subsampling { 1 } else { 2 } as c_int,
+                    NULL,
+                ),
+                Some(SaveOptions::Png {
+                    compression,
+                    palette,
+                }) => vips_image_write_to_buffer(
+                    self.0,
+                    suffix.as_ptr(),
+                    &mut output,
+                    &mut length,
+                    c_string("compression")?.as_ptr(),
+                    compression,
+                    c_string("palette")?.as_ptr(),
+                    palette as c_int,
+                    NULL,
+                ),
+                Some(SaveOptions::Webp {
+                    quality,
+                    lossless,
+                    effort,
+                }) => vips_image_write_to_buffer(
+                    self.0,
+                    suffix.as_ptr(),
+                    &mut output,
+                    &mut length,
+                    c_string("Q")?.as_ptr(),
+                    quality,
+                    c_string("lossless")?.as_ptr(),
+                    lossless as c_int,
+                    c_string("effort")?.as_ptr(),
+                    effort,
+                    NULL,
+                ),
+                Some(SaveOptions::Avif { quality, effort }) => vips_image_write_to_buffer(
+                    self.0,
+                    suffix.as_ptr(),
+                    &mut output,
+                    &mut length,
+                    c_string("Q")?.as_ptr(),
+                    quality,
+                    c_string("effort")?.as_ptr(),
+                    effort,
+                    NULL,
+                ),
+                None => vips_image_write_to_buffer(
+                    self.0,
+                    suffix.as_ptr(),
+                    &mut output,
+                    &mut length,
+                    NULL,
+                ),
+            }
+        };
+
+        if result != 0 || output.is_null() {
+            return Err(Error::ImageSaveError(Vips::get_error()));
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(output as *const u8, length).to_vec() };
+        unsafe {
+            g_free(output);
+        }
+
+        Ok(bytes)
+    }
+
+    pub fn get_int(&self, field: &str) -> Result<i32> {
+        let mut output: c_int = 0;
+
+        let result =
+            unsafe { vips_image_get_int(self.0, c_string(field)?.as_ptr(), &mut output) };
+
+        if result != 0 {
+            return Err(Error::ImageMetadataError(Vips::get_error()));
+        }
+
+        Ok(output)
+    }
+
+    pub fn get_double(&self, field: &str) -> Result<f64> {
+        let mut output: f64 = 0.0;
+
+        let result =
+            unsafe { vips_image_get_double(self.0, c_string(field)?.as_ptr(), &mut output) };
+
+        if result != 0 {
+            return Err(Error::ImageMetadataError(Vips::get_error()));
+        }
+
+        Ok(output)
+    }
+
+    pub fn get_string(&self, field: &str) -> Result<String> {
+        let mut output: *const std::os::raw::c_char = null_mut();
+
+        let result =
+            unsafe { vips_image_get_string(self.0, c_string(field)?.as_ptr(), &mut output) };
+
+        if result != 0 || output.is_null() {
+            return Err(Error::ImageMetadataError(Vips::get_error()));
+        }
+
+        let value = unsafe { CStr::from_ptr(output) }.to_string_lossy().into_owned();
+        Ok(value)
+    }
+
+    pub fn orientation(&self) -> i32 {
+        self.get_int("orientation").unwrap_or(1)
+    }
+
+    pub fn icc_profile(&self) -> Option<Vec<u8>> {
+        self.get_blob("icc-profile-data").ok()
+    }
+
+    pub fn exif_field(&self, tag: &str) -> Option<String> {
+        self.get_string(&format!("exif-ifd0-{tag}")).ok()
+    }
+
+    pub fn remove(&self, field: &str) -> Result<bool> {
+        Ok(unsafe { vips_image_remove(self.0, c_string(field)?.as_ptr()) == 1 })
+    }
+
+    pub fn autorotate(&self) -> Result<Self> {
+        let mut output_image: *mut crate::bindings::VipsImage = null_mut();
+
+        let result = unsafe { vips_autorot(self.0, &mut output_image, NULL) };
+
+        if result != 0 || output_image.is_null() {
+            return Err(Error::ImageLoadError(Vips::get_error()));
+        }
+
+        Ok(VipsImage(output_image, None, None))
+    }
+
     pub fn is_transparent(&self) -> bool {
         unsafe { vips_image_hasalpha(self.0) == 1 }
     }
@@ -204,6 +785,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn it_creates_a_new_image_from_a_buffer() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let bytes = std::fs::read("data/example.jpg").unwrap();
+        let image = VipsImage::new_from_buffer(
+            &bytes,
+            FromBufferOptions {
+                access: VipsAccess::Last,
+                memory: true,
+            }
+            .into(),
+        );
+
+        if let Err(e) = image {
+            panic!("{e}");
+        }
+
+        let image = image.unwrap();
+        assert_eq!(image.get_dimensions(), (4000, 5328));
+    }
+
     #[test]
     fn it_creates_a_new_image_from_svg() {
         let vips = Vips::new("picturium").unwrap();
@@ -313,6 +917,198 @@ mod tests {
         assert_eq!(image.is_transparent(), true);
     }
 
+    #[test]
+    fn it_reads_structured_metadata() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let image = VipsImage::new_from_file("data/example.jpg", None).unwrap();
+
+        assert_eq!(image.orientation(), 1);
+        assert!(image.get_int("width").is_ok());
+    }
+
+    #[test]
+    fn it_removes_a_metadata_field_and_autorotates() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let image = VipsImage::new_from_file("data/example.jpg", None).unwrap();
+
+        if image.has_property("orientation").unwrap_or(false) {
+            assert!(image.remove("orientation").unwrap());
+        }
+
+        let rotated = image.autorotate();
+
+        if let Err(e) = rotated {
+            panic!("{e}");
+        }
+    }
+
+    #[test]
+    fn it_writes_an_image_to_a_file() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let image = VipsImage::new_from_file("data/example.jpg", None).unwrap();
+        let output_path = std::env::temp_dir().join("picturium_write_to_file_test.jpg");
+
+        let result = image.write_to_file(
+            output_path.to_str().unwrap(),
+            SaveOptions::Jpeg {
+                quality: 80,
+                progressive: true,
+                chroma_subsampling: true,
+            }
+            .into(),
+        );
+
+        if let Err(e) = result {
+            panic!("{e}");
+        }
+
+        let written = VipsImage::new_from_file(output_path.to_str().unwrap(), None);
+        std::fs::remove_file(output_path).ok();
+
+        if let Err(e) = written {
+            panic!("{e}");
+        }
+
+        assert_eq!(written.unwrap().get_dimensions(), image.get_dimensions());
+    }
+
+    #[test]
+    fn it_writes_an_image_to_a_buffer() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let image = VipsImage::new_from_file("data/example.jpg", None).unwrap();
+
+        let subsampled = image
+            .write_to_buffer(
+                ".jpg",
+                SaveOptions::Jpeg {
+                    quality: 80,
+                    progressive: false,
+                    chroma_subsampling: true,
+                }
+                .into(),
+            )
+            .unwrap();
+
+        let full_chroma = image
+            .write_to_buffer(
+                ".jpg",
+                SaveOptions::Jpeg {
+                    quality: 80,
+                    progressive: false,
+                    chroma_subsampling: false,
+                }
+                .into(),
+            )
+            .unwrap();
+
+        assert_ne!(subsampled.len(), full_chroma.len());
+
+        let decoded = VipsImage::new_from_buffer(&full_chroma, None);
+
+        if let Err(e) = decoded {
+            panic!("{e}");
+        }
+
+        assert_eq!(decoded.unwrap().get_dimensions(), image.get_dimensions());
+
+        let bytes = image.write_to_buffer(
+            ".png",
+            SaveOptions::Png {
+                compression: 6,
+                palette: false,
+            }
+            .into(),
+        );
+
+        if let Err(e) = bytes {
+            panic!("{e}");
+        }
+
+        assert!(!bytes.unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "heif")]
+    fn it_creates_a_new_image_from_heif() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let image = VipsImage::new_from_heif("data/example.heic", None);
+
+        if let Err(e) = image {
+            panic!("{e}");
+        }
+    }
+
+    #[test]
+    fn it_returns_an_error_for_an_unreadable_raw_file() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let image = VipsImage::new_from_raw("data/does_not_exist.cr2");
+        assert!(image.is_err());
+    }
+
+    #[test]
+    fn it_creates_a_thumbnail_from_a_file() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let thumbnail = VipsImage::thumbnail("data/example.jpg", 200, None);
+
+        if let Err(e) = thumbnail {
+            panic!("{e}");
+        }
+
+        let thumbnail = thumbnail.unwrap();
+        assert_eq!(thumbnail.get_width(), 200);
+    }
+
+    #[test]
+    fn it_creates_a_thumbnail_from_a_buffer() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let bytes = std::fs::read("data/example.jpg").unwrap();
+        let thumbnail = VipsImage::thumbnail_buffer(&bytes, 200, None);
+
+        if let Err(e) = thumbnail {
+            panic!("{e}");
+        }
+
+        let thumbnail = thumbnail.unwrap();
+        assert_eq!(thumbnail.get_width(), 200);
+    }
+
+    #[test]
+    fn it_loads_a_single_page_from_a_document() {
+        let vips = Vips::new("picturium").unwrap();
+        vips.check_leaks();
+
+        let page = VipsImage::new_from_document(
+            "data/document.pdf",
+            DocumentOptions {
+                page: 1,
+                n: 1,
+                dpi: 150.0,
+                ..DocumentOptions::default()
+            }
+            .into(),
+        );
+
+        if let Err(e) = page {
+            panic!("{e}");
+        }
+    }
+
     #[test]
     fn it_returns_number_of_pages() {
         let vips = Vips::new("picturium").unwrap();